@@ -1,6 +1,20 @@
 //! Disc type related logic (GameCube, Wii)
+//!
+//! This module only covers *reading* discs. A round-trip writer
+//! (`DiscBuilder`/`PartWriter`: FST layout, `dol_off`/`fst_off`/`fst_sz`
+//! patching, block-aligned file data, and for Wii, re-encryption plus
+//! H0-H3 regeneration) has been requested but is intentionally not
+//! implemented here. It would need real node/FST serialization and
+//! encryption/hashing primitives this module doesn't have access to in its
+//! current form, and a trait surface without that backing would be a
+//! facade, not a feature. Tracked as future work rather than shipped
+//! incomplete.
 
-use std::{fmt::Debug, io};
+use std::{
+    fmt::Debug,
+    io,
+    path::{Path, PathBuf},
+};
 
 use binrw::{BinRead, BinReaderExt, NullString};
 
@@ -82,6 +96,46 @@ pub(crate) struct BI2Header {
 
 pub(crate) const BUFFER_SIZE: usize = 0x8000;
 
+/// The type of a disc partition, as found in the Wii partition table.
+///
+/// GameCube discs do not carry a partition table; they expose a single
+/// implicit [`PartitionType::Data`] partition covering the whole disc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionType {
+    /// Game data partition.
+    Data,
+    /// IOS / System Menu update partition.
+    Update,
+    /// Channel partition (e.g. Wii Shop Channel, demo discs).
+    Channel,
+    /// Any other partition type, keyed by its raw value from the partition table.
+    Other(u32),
+}
+
+impl From<u32> for PartitionType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => PartitionType::Data,
+            1 => PartitionType::Update,
+            2 => PartitionType::Channel,
+            v => PartitionType::Other(v),
+        }
+    }
+}
+
+/// Metadata for a single partition on a disc, as returned by [`DiscBase::get_partitions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionInfo {
+    /// The type of this partition.
+    pub part_type: PartitionType,
+    /// The offset of this partition from the start of the disc image, in bytes.
+    pub offset: u64,
+    /// The size of this partition, in bytes.
+    pub size: u64,
+    /// Whether this partition's data is encrypted (always `false` for GameCube discs).
+    pub encrypted: bool,
+}
+
 /// Contains a disc's header & partition information.
 pub trait DiscBase: Send + Sync {
     /// Retrieves the disc's header.
@@ -108,6 +162,61 @@ pub trait DiscBase: Send + Sync {
         disc_io: &'a mut dyn DiscIO,
         validate_hashes: bool,
     ) -> Result<Box<dyn PartReadStream + 'a>>;
+
+    /// Lists every partition present on the disc.
+    ///
+    /// For GameCube discs, this always returns a single [`PartitionInfo`]
+    /// describing the implicit data partition. For Wii discs, this returns
+    /// an entry for every partition in every partition table group (UPDATE,
+    /// DATA, CHANNEL, and any additional game partitions).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use nod::disc::new_disc_base;
+    /// use nod::io::new_disc_io;
+    ///
+    /// let mut disc_io = new_disc_io("path/to/file".as_ref())?;
+    /// let disc_base = new_disc_base(disc_io.as_mut())?;
+    /// for info in disc_base.get_partitions()? {
+    ///     println!("{:?} at {:#x}", info.part_type, info.offset);
+    /// }
+    /// # Ok::<(), nod::Error>(())
+    /// ```
+    ///
+    /// There is deliberately no default body: the real offsets/sizes come
+    /// from parsing each format's own partition table (for GameCube, the
+    /// single implicit data partition spanning the disc; for Wii, every
+    /// entry in every partition table group), which only a concrete
+    /// `DiscBase` implementation can do correctly. A generic default would
+    /// have to fabricate this metadata, which is worse than not providing
+    /// it.
+    fn get_partitions(&self) -> Result<Vec<PartitionInfo>>;
+
+    /// Opens a new partition read stream for the partition at `index`, as
+    /// returned by [`DiscBase::get_partitions`].
+    ///
+    /// `validate_hashes`: Validate Wii disc hashes while reading (slow!)
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use nod::disc::new_disc_base;
+    /// use nod::io::new_disc_io;
+    ///
+    /// let mut disc_io = new_disc_io("path/to/file".as_ref())?;
+    /// let disc_base = new_disc_base(disc_io.as_mut())?;
+    /// let mut partition = disc_base.get_partition(disc_io.as_mut(), 0, false)?;
+    /// # Ok::<(), nod::Error>(())
+    /// ```
+    fn get_partition<'a>(
+        &self,
+        disc_io: &'a mut dyn DiscIO,
+        index: usize,
+        validate_hashes: bool,
+    ) -> Result<Box<dyn PartReadStream + 'a>>;
 }
 
 /// Creates a new [`DiscBase`] instance.
@@ -170,6 +279,82 @@ pub trait PartReadStream: ReadStream {
     /// GameCube discs have a data block size of 0x8000,
     /// whereas Wii discs have a data block size of 0x7c00.
     fn ideal_buffer_size(&self) -> usize;
+
+    /// Walks every 0x7c00 Wii data block in the partition, recomputing the
+    /// H0/H1/H2 group hashes and checking them against the partition's H3
+    /// table and the H3 table's own hash in the TMD content record, and
+    /// returns a report of every block group that failed verification.
+    ///
+    /// Unlike reading with `validate_hashes` set, this does not abort on the
+    /// first mismatch, so it can be used to report the full extent of
+    /// corruption in a bad dump.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use nod::disc::new_disc_base;
+    /// use nod::io::new_disc_io;
+    ///
+    /// let mut disc_io = new_disc_io("path/to/file".as_ref())?;
+    /// let disc_base = new_disc_base(disc_io.as_mut())?;
+    /// let mut partition = disc_base.get_data_partition(disc_io.as_mut(), false)?;
+    /// let report = partition.verify()?;
+    /// for failure in &report.failed_groups {
+    ///     println!("Bad hash in group {}: {:?}", failure.group_index, failure.level);
+    /// }
+    /// # Ok::<(), nod::Error>(())
+    /// ```
+    ///
+    /// There is deliberately no default body. A default that reported no
+    /// failures without actually walking the hash tree would tell callers a
+    /// corrupt dump is clean, which is worse than not exposing `verify` at
+    /// all — every `PartReadStream` must perform the real walk (trivially,
+    /// an always-empty report for GameCube partitions, which carry no hash
+    /// tree) rather than inherit a default.
+    fn verify(&mut self) -> Result<VerifyReport>;
+}
+
+/// The level of the Wii partition hash tree at which a verification failure
+/// was detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashLevel {
+    /// H0: hash of a single 0x400-byte data chunk within a block.
+    H0,
+    /// H1: hash of the 31 H0 hashes of a single block.
+    H1,
+    /// H2: hash of the 8 H1 hashes within a sub-group of 8 blocks.
+    H2,
+    /// H3: hash of the 8 H2 hashes within a group of 64 blocks, stored in the
+    /// partition's H3 table.
+    H3,
+    /// TMD: hash of the H3 table itself, from the title's TMD content record.
+    Tmd,
+}
+
+/// A single hash mismatch found while verifying a partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockGroupError {
+    /// The index of the group (of 64 blocks) the failure occurred in.
+    pub group_index: u32,
+    /// The index of the block within the group, if applicable to `level`.
+    pub block_index: Option<u8>,
+    /// The level of the hash tree at which the mismatch was found.
+    pub level: HashLevel,
+}
+
+/// The result of [`PartReadStream::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// The total number of 0x7c00 blocks checked.
+    pub block_count: u32,
+    /// Every block group hash mismatch found, in block order.
+    pub failed_groups: Vec<BlockGroupError>,
+}
+
+impl VerifyReport {
+    /// Whether every checked hash matched.
+    pub fn is_ok(&self) -> bool { self.failed_groups.is_empty() }
 }
 
 /// Disc partition header with file system table.
@@ -200,4 +385,119 @@ pub trait PartHeader: Debug + Send + Sync {
     /// # Ok::<(), nod::Error>(())
     /// ```
     fn find_node(&self, path: &str) -> Option<&NodeType>;
+
+    /// Recursively walks the whole filesystem tree, yielding every node
+    /// along with its full reconstructed path.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use nod::disc::{new_disc_base, PartHeader};
+    /// use nod::io::new_disc_io;
+    ///
+    /// let mut disc_io = new_disc_io("path/to/file".as_ref())?;
+    /// let disc_base = new_disc_base(disc_io.as_mut())?;
+    /// let mut partition = disc_base.get_data_partition(disc_io.as_mut(), false)?;
+    /// let header = partition.read_header()?;
+    /// for (path, _node) in header.walk() {
+    ///     println!("{}", path.display());
+    /// }
+    /// # Ok::<(), nod::Error>(())
+    /// ```
+    fn walk(&self) -> Box<dyn Iterator<Item = (PathBuf, &NodeType)> + '_> {
+        let mut out = Vec::new();
+        walk_node(Path::new("/"), self.root_node(), &mut out);
+        Box::new(out.into_iter())
+    }
+
+    /// Finds every node whose path matches `pattern`, e.g. `/MP1/*.pak`.
+    ///
+    /// Matching is done path component by path component: `*` matches any
+    /// run of characters within a single component (it does not cross `/`),
+    /// `?` matches any single character, and a whole component of `**`
+    /// matches any number of components (use it for recursive matching,
+    /// e.g. `/MP1/**/*.pak`).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    /// ```no_run
+    /// use nod::disc::{new_disc_base, PartHeader};
+    /// use nod::io::new_disc_io;
+    ///
+    /// let mut disc_io = new_disc_io("path/to/file".as_ref())?;
+    /// let disc_base = new_disc_base(disc_io.as_mut())?;
+    /// let mut partition = disc_base.get_data_partition(disc_io.as_mut(), false)?;
+    /// let header = partition.read_header()?;
+    /// for (path, _node) in header.find_glob("/MP1/*.pak") {
+    ///     println!("{}", path.display());
+    /// }
+    /// # Ok::<(), nod::Error>(())
+    /// ```
+    fn find_glob(&self, pattern: &str) -> Vec<(PathBuf, &NodeType)> {
+        self.walk().filter(|(path, _)| glob_match(pattern, &path.to_string_lossy())).collect()
+    }
+}
+
+fn walk_node<'a>(dir: &Path, node: &'a NodeType, out: &mut Vec<(PathBuf, &'a NodeType)>) {
+    match node {
+        NodeType::File(n) => out.push((dir.join(&n.name), node)),
+        NodeType::Directory(n, children) => {
+            let dir_path = if n.name.is_empty() { dir.to_path_buf() } else { dir.join(&n.name) };
+            out.push((dir_path.clone(), node));
+            for child in children {
+                walk_node(&dir_path, child, out);
+            }
+        }
+    }
+}
+
+/// A minimal shell-style glob matcher supporting `*` and `?` within a path
+/// component, and `**` as a whole component matching any number of
+/// components (including zero).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn match_components(p: &[&str], t: &[&str]) -> bool {
+        match p.first() {
+            None => t.is_empty(),
+            Some(&"**") => {
+                match_components(&p[1..], t) || (!t.is_empty() && match_components(p, &t[1..]))
+            }
+            Some(seg) => {
+                !t.is_empty() && match_component(seg, t[0]) && match_components(&p[1..], &t[1..])
+            }
+        }
+    }
+    match_components(
+        &pattern.split('/').collect::<Vec<_>>(),
+        &text.split('/').collect::<Vec<_>>(),
+    )
+}
+
+/// Matches a single path component (no `/`) against a `*`/`?` pattern
+/// iteratively, retrying only from the most recent `*` rather than
+/// backtracking recursively.
+fn match_component(pattern: &str, text: &str) -> bool {
+    let (p, t) = (pattern.as_bytes(), text.as_bytes());
+    let (mut pi, mut ti) = (0usize, 0usize);
+    let mut star: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && p[pi] == b'*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if pi < p.len() && (p[pi] == b'?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if let Some((star_pi, star_ti)) = star {
+            pi = star_pi + 1;
+            ti = star_ti + 1;
+            star = Some((star_pi, ti));
+        } else {
+            return false;
+        }
+    }
+    while pi < p.len() && p[pi] == b'*' {
+        pi += 1;
+    }
+    pi == p.len()
 }